@@ -0,0 +1,247 @@
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::{broadcast, mpsc, oneshot};
+
+use crate::{get_sidecar_path, is_dev_mode, DEV_PROJECT_PATH, DEV_PYTHON_PATH};
+
+const EVENT_CHANNEL_CAPACITY: usize = 64;
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Commands the Tauri layer can send to the backend daemon.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "cmd", rename_all = "snake_case")]
+pub enum DaemonCommand {
+    StartRecording { device: Option<i32> },
+    StopAndTranscribe { output: String },
+    LoadModel { model: String },
+    CheckModel { model: String },
+}
+
+impl DaemonCommand {
+    /// The daemon (Python in dev, sidecar in prod) expects a flat
+    /// `{"cmd": "...", ...}` object on stdin, so typed commands get lowered
+    /// to that shape right before they're written.
+    fn to_wire(&self) -> serde_json::Value {
+        match self {
+            DaemonCommand::StartRecording { device } => serde_json::json!({
+                "cmd": "start_recording",
+                "device": device,
+            }),
+            DaemonCommand::StopAndTranscribe { output } => serde_json::json!({
+                "cmd": "stop_and_transcribe",
+                "output": output,
+            }),
+            DaemonCommand::LoadModel { model } => serde_json::json!({
+                "cmd": "load_model",
+                "model": model,
+            }),
+            DaemonCommand::CheckModel { model } => serde_json::json!({
+                "cmd": "check_model",
+                "model": model,
+            }),
+        }
+    }
+}
+
+/// Events decoded from the daemon's stdout and re-broadcast to subscribers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DaemonEvent {
+    AudioLevel(f64),
+    /// Raw PCM samples (16 kHz mono, i16) streamed while recording, consumed
+    /// by the streaming transcription ring buffer.
+    AudioChunk(Vec<i16>),
+    Status(String),
+    Transcription {
+        text: String,
+        copied: bool,
+        typed: bool,
+        transcription_time: f64,
+    },
+    Error(String),
+}
+
+impl DaemonEvent {
+    /// The daemon's stdout lines are untagged JSON (`{"audio_level": ...}`,
+    /// `{"status": ...}`, ...), so we sniff the shape of each line instead of
+    /// relying on serde's tagging to decode it.
+    fn from_raw(json: &serde_json::Value) -> Option<Self> {
+        if let Some(level) = json.get("audio_level").and_then(|l| l.as_f64()) {
+            return Some(DaemonEvent::AudioLevel(level));
+        }
+        if let Some(samples) = json.get("audio_chunk").and_then(|c| c.as_array()) {
+            let samples = samples.iter().filter_map(|v| v.as_i64()).map(|v| v as i16).collect();
+            return Some(DaemonEvent::AudioChunk(samples));
+        }
+        if let Some(text) = json.get("text").and_then(|t| t.as_str()) {
+            return Some(DaemonEvent::Transcription {
+                text: text.to_string(),
+                copied: json.get("copied").and_then(|c| c.as_bool()).unwrap_or(false),
+                typed: json.get("typed").and_then(|t| t.as_bool()).unwrap_or(false),
+                transcription_time: json
+                    .get("transcription_time")
+                    .and_then(|t| t.as_f64())
+                    .unwrap_or(0.0),
+            });
+        }
+        if let Some(error) = json.get("error").and_then(|e| e.as_str()) {
+            return Some(DaemonEvent::Error(error.to_string()));
+        }
+        if let Some(status) = json.get("status").and_then(|s| s.as_str()) {
+            return Some(DaemonEvent::Status(status.to_string()));
+        }
+        None
+    }
+}
+
+/// Handle to the supervised daemon subprocess. Cloning is cheap: it's just a
+/// command sender plus a broadcast sender new subscribers attach to.
+#[derive(Clone)]
+pub struct DaemonHandle {
+    commands: mpsc::Sender<DaemonCommand>,
+    events: broadcast::Sender<DaemonEvent>,
+}
+
+impl DaemonHandle {
+    pub fn subscribe(&self) -> broadcast::Receiver<DaemonEvent> {
+        self.events.subscribe()
+    }
+
+    pub async fn send(&self, cmd: DaemonCommand) -> Result<(), String> {
+        self.commands
+            .send(cmd)
+            .await
+            .map_err(|_| "daemon command channel closed".to_string())
+    }
+}
+
+fn spawn_backend_process(app: &AppHandle) -> Option<std::process::Child> {
+    let mut cmd: Command;
+
+    if let Some(sidecar_path) = get_sidecar_path(app) {
+        cmd = Command::new(sidecar_path);
+    } else if is_dev_mode() {
+        let script_path = format!("{}/python/backend_daemon.py", DEV_PROJECT_PATH);
+        cmd = Command::new(DEV_PYTHON_PATH);
+        cmd.arg(&script_path);
+    } else {
+        log::error!("Cannot determine daemon path!");
+        return None;
+    }
+
+    cmd.stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    match cmd.spawn() {
+        Ok(child) => {
+            log::info!("Started backend daemon (PID: {:?})", child.id());
+            Some(child)
+        }
+        Err(e) => {
+            log::error!("Failed to start backend daemon: {}", e);
+            None
+        }
+    }
+}
+
+/// Spawns the daemon subprocess behind a supervisor task that restarts it
+/// with exponential backoff whenever it exits (stdout EOF or a non-zero
+/// status), re-issuing the last `LoadModel` command so transcription keeps
+/// working after a backend crash.
+pub fn spawn_supervised(app: AppHandle, initial_model: String) -> DaemonHandle {
+    let (command_tx, mut command_rx) = mpsc::channel::<DaemonCommand>(32);
+    let (event_tx, _) = broadcast::channel::<DaemonEvent>(EVENT_CHANNEL_CAPACITY);
+
+    let handle = DaemonHandle {
+        commands: command_tx,
+        events: event_tx.clone(),
+    };
+
+    tauri::async_runtime::spawn(async move {
+        let mut backoff = INITIAL_BACKOFF;
+        let mut last_model = Some(initial_model);
+        // Commands that arrive while we're between daemon instances are
+        // replayed against the freshly spawned one.
+        let mut pending: Vec<DaemonCommand> = Vec::new();
+
+        loop {
+            let Some(mut child) = spawn_backend_process(&app) else {
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+                continue;
+            };
+            let session_started = std::time::Instant::now();
+
+            let mut stdin = child.stdin.take().expect("daemon stdin piped");
+            let stdout = child.stdout.take().expect("daemon stdout piped");
+
+            let (eof_tx, mut eof_rx) = oneshot::channel::<()>();
+            let event_tx_reader = event_tx.clone();
+            let reader_handle = std::thread::spawn(move || {
+                let reader = BufReader::new(stdout);
+                for line in reader.lines().flatten() {
+                    if let Ok(json) = serde_json::from_str::<serde_json::Value>(&line) {
+                        if let Some(event) = DaemonEvent::from_raw(&json) {
+                            let _ = event_tx_reader.send(event);
+                        }
+                    }
+                }
+                log::warn!("Daemon stdout reader ended");
+                let _ = eof_tx.send(());
+            });
+
+            if let Some(model) = &last_model {
+                pending.push(DaemonCommand::LoadModel {
+                    model: model.clone(),
+                });
+            }
+
+            'session: loop {
+                let cmd = if let Some(cmd) = pending.pop() {
+                    cmd
+                } else {
+                    tokio::select! {
+                        maybe_cmd = command_rx.recv() => match maybe_cmd {
+                            Some(cmd) => cmd,
+                            None => return, // App is shutting down.
+                        },
+                        _ = &mut eof_rx => break 'session,
+                    }
+                };
+
+                if let DaemonCommand::LoadModel { model } = &cmd {
+                    last_model = Some(model.clone());
+                }
+
+                let wire = cmd.to_wire().to_string();
+                if writeln!(stdin, "{}", wire).is_err() || stdin.flush().is_err() {
+                    log::error!("Failed to write to daemon stdin, assuming it crashed");
+                    pending.push(cmd);
+                    break 'session;
+                }
+            }
+
+            let _ = child.kill();
+            let _ = child.wait();
+            let _ = reader_handle.join();
+
+            // Only a daemon that stayed up for a while resets the backoff, so
+            // a crash loop keeps doubling the delay between restarts.
+            if session_started.elapsed() >= MAX_BACKOFF {
+                backoff = INITIAL_BACKOFF;
+            }
+
+            log::error!("Daemon crashed, restarting in {:?}", backoff);
+            let _ = app.emit("daemon_crashed", ());
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    });
+
+    handle
+}
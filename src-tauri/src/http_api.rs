@@ -0,0 +1,104 @@
+use std::io::Read;
+
+use serde::Serialize;
+use tauri::AppHandle;
+use tiny_http::{Header, Method, Response, Server};
+
+use crate::{transcribe_pcm, SharedState};
+
+#[derive(Serialize)]
+struct TranscribeResponse {
+    text: String,
+}
+
+#[derive(Serialize)]
+struct StatusResponse {
+    model_loaded: bool,
+    model: String,
+}
+
+/// Starts the optional local HTTP API (`POST /transcribe`, `GET /status`) if
+/// `Config::http_api_enabled` is set, binding to `127.0.0.1` on the
+/// configured port. tiny_http's accept loop is synchronous, so it runs on
+/// its own thread and bridges into the async backend with
+/// `tauri::async_runtime::block_on` per request, the same way `run()`
+/// bridges into `BackendState` with `blocking_lock`.
+pub fn spawn(app: AppHandle, state: SharedState) {
+    let (enabled, port, token) = {
+        let config = state.blocking_lock().config.clone();
+        (config.http_api_enabled, config.http_api_port, config.http_api_token)
+    };
+
+    if !enabled {
+        return;
+    }
+
+    let address = format!("127.0.0.1:{}", port);
+    let server = match Server::http(&address) {
+        Ok(server) => server,
+        Err(e) => {
+            log::error!("Failed to start local HTTP API on {}: {}", address, e);
+            return;
+        }
+    };
+
+    log::info!("Local HTTP API listening on {}", address);
+
+    std::thread::spawn(move || {
+        for request in server.incoming_requests() {
+            handle_request(&app, &state, &token, request);
+        }
+    });
+}
+
+fn handle_request(app: &AppHandle, state: &SharedState, token: &str, mut request: tiny_http::Request) {
+    if !authorized(&request, token) {
+        let _ = request.respond(Response::from_string("unauthorized").with_status_code(401));
+        return;
+    }
+
+    match (request.method(), request.url()) {
+        (Method::Get, "/status") => {
+            let (model_loaded, model) = {
+                let guard = state.blocking_lock();
+                (guard.model_loaded, guard.config.model.clone())
+            };
+            respond_json(request, &StatusResponse { model_loaded, model });
+        }
+        (Method::Post, "/transcribe") => {
+            let mut body = Vec::new();
+            if request.as_reader().read_to_end(&mut body).is_err() {
+                let _ = request.respond(Response::from_string("failed to read body").with_status_code(400));
+                return;
+            }
+
+            match tauri::async_runtime::block_on(transcribe_pcm(app, &body)) {
+                Some(text) => respond_json(request, &TranscribeResponse { text }),
+                None => {
+                    let _ = request.respond(Response::from_string("transcription failed").with_status_code(500));
+                }
+            }
+        }
+        _ => {
+            let _ = request.respond(Response::from_string("not found").with_status_code(404));
+        }
+    }
+}
+
+fn respond_json<T: Serialize>(request: tiny_http::Request, body: &T) {
+    let json = serde_json::to_string(body).unwrap_or_default();
+    let header = Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).expect("valid header");
+    let _ = request.respond(Response::from_string(json).with_header(header));
+}
+
+/// Requires an exact `X-Auth-Token` match against the configured token, so a
+/// blank (default) token authorizes nothing until the user sets one.
+fn authorized(request: &tiny_http::Request, token: &str) -> bool {
+    if token.is_empty() {
+        return false;
+    }
+    request
+        .headers()
+        .iter()
+        .any(|h| h.field.equiv("X-Auth-Token") && h.value.as_str() == token)
+}
@@ -1,8 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::io::{BufRead, BufReader, Write};
 use std::path::PathBuf;
-use std::process::{Command, Stdio};
+use std::process::Command;
 use std::sync::Arc;
 use std::time::Instant;
 use tauri::{
@@ -10,9 +9,21 @@ use tauri::{
     AppHandle, Emitter, Manager,
 };
 use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Modifiers, Shortcut, ShortcutState};
+use tauri_plugin_shell::process::{CommandChild, CommandEvent};
 use tauri_plugin_shell::ShellExt;
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::Receiver;
 use tokio::sync::Mutex;
 
+mod daemon;
+mod http_api;
+mod models;
+mod streaming;
+mod window_ext;
+
+use daemon::{DaemonCommand, DaemonEvent, DaemonHandle};
+use window_ext::WindowExt;
+
 // Dev mode fallback paths (only used if sidecar not available)
 const DEV_PYTHON_PATH: &str = "/Users/thibault/Documents/WORK/super-whisper/.venv/bin/python";
 const DEV_PROJECT_PATH: &str = "/Users/thibault/Documents/WORK/super-whisper";
@@ -21,7 +32,7 @@ fn is_dev_mode() -> bool {
     cfg!(debug_assertions)
 }
 
-fn get_sidecar_path(app: &AppHandle) -> Option<PathBuf> {
+pub(crate) fn get_sidecar_path(app: &AppHandle) -> Option<PathBuf> {
     // In bundled app, sidecar is in the same directory as the main executable (Contents/MacOS/)
     // Tauri strips the target triple suffix when bundling
     
@@ -101,9 +112,11 @@ fn save_config_to_file(config: &Config) -> Result<(), String> {
 struct BackendState {
     is_recording: bool,
     recording_start: Option<Instant>,
-    daemon_stdin: Option<std::process::ChildStdin>,
+    daemon: Option<DaemonHandle>,
     config: Config,
     model_loaded: bool,
+    streaming: Option<streaming::StreamingHandle>,
+    streaming_audio_tx: Option<mpsc::Sender<Vec<i16>>>,
 }
 
 impl Default for BackendState {
@@ -111,14 +124,16 @@ impl Default for BackendState {
         Self {
             is_recording: false,
             recording_start: None,
-            daemon_stdin: None,
+            daemon: None,
             config: load_config_from_file(),
             model_loaded: false,
+            streaming: None,
+            streaming_audio_tx: None,
         }
     }
 }
 
-type SharedState = Arc<Mutex<BackendState>>;
+pub(crate) type SharedState = Arc<Mutex<BackendState>>;
 
 // Config structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -131,6 +146,55 @@ pub struct Config {
     pub output_mode: String,
     pub typing_speed: f32,
     pub providers: Vec<String>,
+    /// Normalized RMS level below which the mic is considered silent.
+    pub silence_threshold: f32,
+    /// Multiplier applied to each incoming audio level before it's compared
+    /// against `silence_threshold`, so a quiet mic/room can be compensated for.
+    pub mic_sensitivity: f32,
+    /// How long the level must stay below `silence_threshold` before VAD
+    /// auto-stops the recording.
+    pub silence_timeout_ms: u32,
+    /// Minimum cumulative speech time required before VAD will auto-stop, so
+    /// a single cough near the start of a recording doesn't end it early.
+    pub min_speech_ms: u32,
+    /// Keep the overlay visible when the user switches Spaces or focuses a
+    /// fullscreen app, instead of it disappearing mid-dictation.
+    pub visible_on_all_workspaces: bool,
+    /// When true the overlay ignores cursor events, so clicks pass through
+    /// to whatever is underneath it. Focus handling does not depend on this
+    /// flag.
+    pub overlay_click_through: bool,
+    pub overlay_position: OverlayPosition,
+    pub record_mode: RecordMode,
+    /// Enables the local HTTP API (`POST /transcribe`, `GET /status`) on
+    /// `127.0.0.1`, off by default so SuperWhisper doesn't open a socket
+    /// unless the user opts in.
+    pub http_api_enabled: bool,
+    pub http_api_port: u16,
+    /// Required `X-Auth-Token` header value for every HTTP API request. No
+    /// requests are authorized until the user sets a non-empty token.
+    pub http_api_token: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlayPosition {
+    TopCenter,
+    BottomCenter,
+    Center,
+    /// Anchored just below the cursor, on whichever monitor the cursor is
+    /// currently on, instead of a fixed spot on the overlay window's monitor.
+    NearCursor,
+}
+
+/// Whether the record hotkey behaves as push-to-talk (hold to record,
+/// release to transcribe) or as a toggle (press to start, press again to
+/// stop).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordMode {
+    PushToTalk,
+    Toggle,
 }
 
 impl Default for Config {
@@ -144,6 +208,17 @@ impl Default for Config {
             output_mode: "clipboard".to_string(),
             typing_speed: 0.01,
             providers: vec!["CPUExecutionProvider".to_string()],
+            silence_threshold: 0.02,
+            mic_sensitivity: 1.0,
+            silence_timeout_ms: 1500,
+            min_speech_ms: 300,
+            visible_on_all_workspaces: true,
+            overlay_click_through: true,
+            overlay_position: OverlayPosition::TopCenter,
+            record_mode: RecordMode::PushToTalk,
+            http_api_enabled: false,
+            http_api_port: 8765,
+            http_api_token: String::new(),
         }
     }
 }
@@ -155,66 +230,120 @@ pub struct AudioDevice {
     pub is_default: bool,
 }
 
-fn get_sidecar_or_python_command(app: Option<&AppHandle>) -> (String, Vec<String>) {
-    // Try sidecar first
-    if let Some(app) = app {
-        if let Some(sidecar_path) = get_sidecar_path(app) {
-            if sidecar_path.exists() {
-                return (sidecar_path.to_string_lossy().to_string(), vec![]);
-            }
-        }
+/// Spawns the backend (bundled sidecar, falling back to the dev-mode Python
+/// script) through the shell plugin, returning the async `CommandEvent`
+/// stream instead of blocking until it exits.
+pub(crate) fn spawn_backend_command(
+    app: &AppHandle,
+    args: Vec<String>,
+) -> Result<(Receiver<CommandEvent>, CommandChild), String> {
+    if let Some(sidecar_path) = get_sidecar_path(app) {
+        return app
+            .shell()
+            .command(sidecar_path)
+            .args(args)
+            .spawn()
+            .map_err(|e| e.to_string());
     }
-    
-    // Fallback to Python in dev mode
+
     if is_dev_mode() {
         let script_path = format!("{}/python/backend_daemon.py", DEV_PROJECT_PATH);
-        return (DEV_PYTHON_PATH.to_string(), vec![script_path]);
+        let mut full_args = vec![script_path];
+        full_args.extend(args);
+        return app
+            .shell()
+            .command(DEV_PYTHON_PATH)
+            .args(full_args)
+            .spawn()
+            .map_err(|e| e.to_string());
     }
-    
-    ("".to_string(), vec![])
+
+    Err("No sidecar or Python available".to_string())
 }
 
-// Tauri commands
-#[tauri::command]
-async fn get_devices(app: AppHandle) -> Result<Vec<AudioDevice>, String> {
-    log::info!("get_devices called");
-    
-    let (cmd_path, mut args) = get_sidecar_or_python_command(Some(&app));
-    
-    if cmd_path.is_empty() {
-        log::error!("No sidecar or Python available");
-        return Ok(vec![]);
+/// Runs a backend invocation to completion, collecting its stdout lines and
+/// logging stderr as it streams in, for commands that just need the final
+/// output rather than incremental progress.
+async fn run_backend_command(app: &AppHandle, args: Vec<String>) -> Result<String, String> {
+    let (mut rx, _child) = spawn_backend_command(app, args)?;
+    let mut stdout = String::new();
+
+    while let Some(event) = rx.recv().await {
+        match event {
+            CommandEvent::Stdout(line) => {
+                stdout.push_str(&String::from_utf8_lossy(&line));
+                stdout.push('\n');
+            }
+            CommandEvent::Stderr(line) => {
+                log::warn!("Backend stderr: {}", String::from_utf8_lossy(&line));
+            }
+            CommandEvent::Error(e) => return Err(e),
+            CommandEvent::Terminated(payload) => {
+                if payload.code.unwrap_or(1) != 0 {
+                    return Err(format!("Backend exited with status {:?}", payload.code));
+                }
+                break;
+            }
+            _ => {}
+        }
     }
-    
-    args.push("--list-devices".to_string());
-    
-    let output = Command::new(&cmd_path)
-        .args(&args)
-        .output();
-    
-    match output {
-        Ok(output) => {
-            if output.status.success() {
-                let stdout = String::from_utf8_lossy(&output.stdout);
-                // Parse the JSON response
-                if let Ok(json) = serde_json::from_str::<serde_json::Value>(&stdout) {
-                    if let Some(devices) = json.get("devices") {
-                        if let Ok(devices) = serde_json::from_value::<Vec<AudioDevice>>(devices.clone()) {
-                            log::info!("Parsed {} audio devices", devices.len());
-                            return Ok(devices);
-                        }
-                    }
+
+    Ok(stdout)
+}
+
+/// Hands raw 16kHz mono PCM to a fresh backend process in its one-shot
+/// `--transcribe-pcm` mode and pulls out the `text` field of its final JSON
+/// line. That process is separate from the daemon, so it does not use the
+/// model the daemon has loaded. Shared by the streaming subsystem's sliding
+/// window decode and the local HTTP API's `/transcribe` endpoint.
+pub(crate) async fn transcribe_pcm(app: &AppHandle, pcm_bytes: &[u8]) -> Option<String> {
+    let (mut rx, mut child) = spawn_backend_command(app, vec!["--transcribe-pcm".to_string()]).ok()?;
+    if child.write(pcm_bytes).is_err() {
+        return None;
+    }
+    drop(child);
+
+    let mut stdout = String::new();
+    while let Some(event) = rx.recv().await {
+        match event {
+            CommandEvent::Stdout(line) => {
+                let line = String::from_utf8_lossy(&line);
+                if !line.trim().is_empty() {
+                    stdout = line.into_owned();
                 }
-            } else {
-                let stderr = String::from_utf8_lossy(&output.stderr);
-                log::error!("Command failed: {}", stderr);
             }
+            CommandEvent::Terminated(_) | CommandEvent::Error(_) => break,
+            _ => {}
         }
+    }
+
+    serde_json::from_str::<serde_json::Value>(&stdout)
+        .ok()
+        .and_then(|json| json.get("text").and_then(|t| t.as_str()).map(str::to_string))
+}
+
+// Tauri commands
+#[tauri::command]
+async fn get_devices(app: AppHandle) -> Result<Vec<AudioDevice>, String> {
+    log::info!("get_devices called");
+
+    let stdout = match run_backend_command(&app, vec!["--list-devices".to_string()]).await {
+        Ok(stdout) => stdout,
         Err(e) => {
             log::error!("Failed to run command: {}", e);
+            return Ok(vec![]);
+        }
+    };
+
+    if let Ok(json) = serde_json::from_str::<serde_json::Value>(&stdout) {
+        if let Some(devices) = json.get("devices") {
+            if let Ok(devices) = serde_json::from_value::<Vec<AudioDevice>>(devices.clone()) {
+                log::info!("Parsed {} audio devices", devices.len());
+                return Ok(devices);
+            }
         }
     }
-    
+
     Ok(vec![])
 }
 
@@ -224,14 +353,23 @@ async fn get_config() -> Result<Config, String> {
 }
 
 #[tauri::command]
-async fn save_config(config: Config, state: tauri::State<'_, SharedState>) -> Result<(), String> {
+async fn save_config(
+    app: AppHandle,
+    config: Config,
+    state: tauri::State<'_, SharedState>,
+) -> Result<(), String> {
     log::info!("Saving config: {:?}", config);
     save_config_to_file(&config)?;
-    
+
     // Update in-memory state
     let mut state = state.lock().await;
-    state.config = config;
-    
+    state.config = config.clone();
+    drop(state);
+
+    if let Some(window) = app.get_webview_window("overlay") {
+        apply_overlay_window_config(&window, &config);
+    }
+
     Ok(())
 }
 
@@ -246,36 +384,39 @@ pub struct ModelStatus {
 #[tauri::command]
 async fn check_model_status(app: AppHandle, model: String) -> Result<ModelStatus, String> {
     log::info!("Checking model status: {}", model);
-    
-    let (cmd_path, mut args) = get_sidecar_or_python_command(Some(&app));
-    
-    if cmd_path.is_empty() {
-        return Ok(ModelStatus {
-            downloaded: false,
-            path: None,
-            size: None,
-            error: Some("No sidecar or Python available".to_string()),
-        });
-    }
-    
-    args.push("--check-model".to_string());
-    args.push(model.clone());
-    
-    let output = Command::new(&cmd_path)
-        .args(&args)
-        .output()
-        .map_err(|e| e.to_string())?;
-    
-    if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        // Parse the JSON response - it may have multiple lines
-        for line in stdout.lines() {
-            if let Ok(status) = serde_json::from_str::<ModelStatus>(line) {
-                return Ok(status);
+
+    let (mut rx, _child) = match spawn_backend_command(
+        &app,
+        vec!["--check-model".to_string(), model.clone()],
+    ) {
+        Ok(v) => v,
+        Err(e) => {
+            return Ok(ModelStatus {
+                downloaded: false,
+                path: None,
+                size: None,
+                error: Some(e),
+            });
+        }
+    };
+
+    // The backend may print a few status lines before the final one.
+    while let Some(event) = rx.recv().await {
+        match event {
+            CommandEvent::Stdout(line) => {
+                let line = String::from_utf8_lossy(&line);
+                if let Ok(status) = serde_json::from_str::<ModelStatus>(&line) {
+                    return Ok(status);
+                }
             }
+            CommandEvent::Stderr(line) => {
+                log::warn!("Backend stderr: {}", String::from_utf8_lossy(&line));
+            }
+            CommandEvent::Terminated(_) | CommandEvent::Error(_) => break,
+            _ => {}
         }
     }
-    
+
     Ok(ModelStatus {
         downloaded: false,
         path: None,
@@ -284,41 +425,168 @@ async fn check_model_status(app: AppHandle, model: String) -> Result<ModelStatus
     })
 }
 
+#[derive(Debug, Clone, Serialize)]
+struct DownloadProgress {
+    downloaded: u64,
+    total: u64,
+    percent: f32,
+}
+
 #[tauri::command]
 async fn download_model(app: AppHandle, model: String) -> Result<(), String> {
     log::info!("Downloading model: {}", model);
     let _ = app.emit("model_download_started", &model);
-    
-    let (cmd_path, mut args) = get_sidecar_or_python_command(Some(&app));
-    
-    if cmd_path.is_empty() {
-        let _ = app.emit("model_download_error", &model);
-        return Err("No sidecar or Python available".to_string());
+
+    let (mut rx, _child) = match spawn_backend_command(
+        &app,
+        vec!["--download-model".to_string(), model.clone()],
+    ) {
+        Ok(v) => v,
+        Err(e) => {
+            let _ = app.emit("model_download_error", &model);
+            return Err(e);
+        }
+    };
+
+    while let Some(event) = rx.recv().await {
+        match event {
+            CommandEvent::Stdout(line) => {
+                let line = String::from_utf8_lossy(&line);
+                let progress = serde_json::from_str::<serde_json::Value>(&line).ok().and_then(|json| {
+                    let downloaded = json.get("downloaded").and_then(|v| v.as_u64())?;
+                    let total = json.get("total").and_then(|v| v.as_u64())?;
+                    Some((downloaded, total))
+                });
+
+                if let Some((downloaded, total)) = progress {
+                    let percent = if total > 0 {
+                        downloaded as f32 / total as f32 * 100.0
+                    } else {
+                        0.0
+                    };
+                    let _ = app.emit(
+                        "model_download_progress",
+                        DownloadProgress {
+                            downloaded,
+                            total,
+                            percent,
+                        },
+                    );
+                } else {
+                    log::info!("Download output: {}", line.trim_end());
+                }
+            }
+            CommandEvent::Stderr(line) => {
+                log::warn!("Backend stderr: {}", String::from_utf8_lossy(&line));
+            }
+            CommandEvent::Error(e) => {
+                log::error!("Model download failed: {}", e);
+                let _ = app.emit("model_download_error", &model);
+                return Err(e);
+            }
+            CommandEvent::Terminated(payload) => {
+                if payload.code.unwrap_or(1) == 0 {
+                    log::info!("Model downloaded: {}", model);
+                    let _ = app.emit("model_download_done", &model);
+                    return Ok(());
+                } else {
+                    log::error!("Model download failed with status {:?}", payload.code);
+                    let _ = app.emit("model_download_error", &model);
+                    return Err(format!("Download failed with status {:?}", payload.code));
+                }
+            }
+            _ => {}
+        }
     }
-    
-    args.push("--download-model".to_string());
-    args.push(model.clone());
-    
-    let output = Command::new(&cmd_path)
-        .args(&args)
-        .output()
-        .map_err(|e| e.to_string())?;
-    
-    if output.status.success() {
-        log::info!("Model downloaded: {}", model);
-        let _ = app.emit("model_download_done", &model);
-        return Ok(());
-    } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        log::error!("Model download failed: {}", stderr);
-        let _ = app.emit("model_download_error", &model);
-        return Err(format!("Download failed: {}", stderr));
+
+    let _ = app.emit("model_download_error", &model);
+    Err("Backend exited without a result".to_string())
+}
+
+/// Applies the overlay-related config (multi-Space visibility, click-through,
+/// anchor position) to the overlay window. Called on startup, from
+/// `show_overlay`, and whenever the config is saved, so changes take effect
+/// without a restart.
+fn apply_overlay_window_config(window: &tauri::WebviewWindow, config: &Config) {
+    let _ = window.set_visible_on_all_workspaces(config.visible_on_all_workspaces);
+    let _ = window.set_ignore_cursor_events(config.overlay_click_through);
+    position_overlay(window, config.overlay_position);
+}
+
+/// The monitor containing the cursor, so the overlay can land on whichever
+/// display the user is actually working on instead of wherever the overlay
+/// window happened to be last. Falls back to `None` (handled by the caller)
+/// if the cursor position or monitor list isn't available.
+fn monitor_for_cursor(window: &tauri::WebviewWindow) -> Option<tauri::Monitor> {
+    let cursor = window.cursor_position().ok()?;
+    window.available_monitors().ok()?.into_iter().find(|monitor| {
+        let pos = monitor.position();
+        let size = monitor.size();
+        cursor.x >= pos.x as f64
+            && cursor.x < (pos.x + size.width as i32) as f64
+            && cursor.y >= pos.y as f64
+            && cursor.y < (pos.y + size.height as i32) as f64
+    })
+}
+
+fn position_overlay(window: &tauri::WebviewWindow, position: OverlayPosition) {
+    let Some(monitor) = monitor_for_cursor(window).or_else(|| window.current_monitor().ok().flatten())
+    else {
+        return;
+    };
+
+    let monitor_pos = monitor.position();
+    let monitor_size = monitor.size();
+    let scale = monitor.scale_factor();
+
+    let window_width = (400.0 * scale).round() as i32;
+    let window_height = (120.0 * scale).round() as i32;
+    let margin = (30.0 * scale).round() as i32;
+
+    let (x, y) = match position {
+        OverlayPosition::TopCenter => (
+            monitor_pos.x + (monitor_size.width as i32 - window_width) / 2,
+            monitor_pos.y + margin,
+        ),
+        OverlayPosition::BottomCenter => (
+            monitor_pos.x + (monitor_size.width as i32 - window_width) / 2,
+            monitor_pos.y + monitor_size.height as i32 - window_height - margin,
+        ),
+        OverlayPosition::Center => (
+            monitor_pos.x + (monitor_size.width as i32 - window_width) / 2,
+            monitor_pos.y + (monitor_size.height as i32 - window_height) / 2,
+        ),
+        OverlayPosition::NearCursor => match window.cursor_position().ok() {
+            Some(cursor) => (cursor.x as i32 - window_width / 2, cursor.y as i32 + margin),
+            None => (
+                monitor_pos.x + (monitor_size.width as i32 - window_width) / 2,
+                monitor_pos.y + margin,
+            ),
+        },
+    };
+
+    let _ = window.set_position(tauri::PhysicalPosition::new(x, y));
+    log::info!("Overlay positioned at ({}, {})", x, y);
+}
+
+/// Re-anchors the overlay on the monitor under the cursor using the
+/// currently configured position, without toggling its visibility.
+/// `start_recording` does the same repositioning itself through
+/// `position_overlay` before it shows the overlay.
+#[tauri::command]
+async fn reposition_overlay(app: AppHandle, state: tauri::State<'_, SharedState>) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window("overlay") {
+        let position = state.lock().await.config.overlay_position;
+        position_overlay(&window, position);
     }
+    Ok(())
 }
 
 #[tauri::command]
-async fn show_overlay(app: AppHandle) -> Result<(), String> {
+async fn show_overlay(app: AppHandle, state: tauri::State<'_, SharedState>) -> Result<(), String> {
     if let Some(window) = app.get_webview_window("overlay") {
+        let config = state.lock().await.config.clone();
+        apply_overlay_window_config(&window, &config);
         window.show().map_err(|e| e.to_string())?;
     }
     Ok(())
@@ -377,7 +645,7 @@ async fn open_accessibility_settings() -> Result<(), String> {
 fn setup_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
     let _tray = TrayIconBuilder::new()
         .icon(app.default_window_icon().unwrap().clone())
-        .tooltip("SuperWhisper - Option+Space to record")
+        .tooltip("SuperWhisper")
         .on_tray_icon_event(|tray, event| {
             if let TrayIconEvent::Click {
                 button: MouseButton::Left,
@@ -397,232 +665,392 @@ fn setup_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-fn start_daemon(app: &AppHandle, state: SharedState) -> bool {
-    let mut cmd: Command;
-    
-    // Try sidecar first (production), fallback to Python (dev)
-    if let Some(sidecar_path) = get_sidecar_path(app) {
-        if sidecar_path.exists() {
-            log::info!("Starting daemon from sidecar: {:?}", sidecar_path);
-            cmd = Command::new(sidecar_path);
-        } else if is_dev_mode() {
-            log::info!("Sidecar not found, using Python in dev mode");
-            let script_path = format!("{}/python/backend_daemon.py", DEV_PROJECT_PATH);
-            cmd = Command::new(DEV_PYTHON_PATH);
-            cmd.arg(&script_path);
-        } else {
-            log::error!("Sidecar not found and not in dev mode!");
+/// Stops an in-progress recording the same way whether it was triggered by
+/// the hotkey release or by VAD silence detection.
+async fn stop_recording(app: &AppHandle, state: &SharedState) {
+    let mut guard = state.lock().await;
+
+    if !guard.is_recording {
+        return;
+    }
+    guard.is_recording = false;
+
+    let duration = guard
+        .recording_start
+        .map(|start| start.elapsed().as_secs_f32())
+        .unwrap_or(0.0);
+
+    log::info!("Recording stopped after {:.1}s", duration);
+
+    let _ = app.emit(
+        "recording_stopped",
+        serde_json::json!({ "duration": duration }),
+    );
+
+    let output_mode = guard.config.output_mode.clone();
+    let daemon = guard.daemon.clone();
+    let streaming = guard.streaming.take();
+    guard.streaming_audio_tx = None;
+    drop(guard);
+
+    if let Some(streaming) = streaming {
+        streaming.stop();
+    }
+
+    if let Some(daemon) = daemon {
+        let _ = app.emit("transcription_started", ());
+        let _ = daemon
+            .send(DaemonCommand::StopAndTranscribe {
+                output: output_mode,
+            })
+            .await;
+    } else {
+        log::error!("Daemon not running!");
+
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        if let Some(window) = app.get_webview_window("overlay") {
+            let _ = window.hide();
+        }
+    }
+}
+
+/// Tracks voice-activity timers across the audio levels streamed while a
+/// recording is in progress, so `spawn_event_forwarder` can auto-stop on
+/// silence without the rest of the daemon event handling knowing about VAD.
+#[derive(Default)]
+struct VadTracker {
+    recording: bool,
+    speech_started_at: Option<Instant>,
+    below_threshold_since: Option<Instant>,
+    speech_duration: std::time::Duration,
+    last_observed_at: Option<Instant>,
+}
+
+impl VadTracker {
+    /// Resets timers when a recording session starts or ends, and returns
+    /// `true` if auto-stop should fire for this audio level.
+    fn observe(&mut self, level: f64, config: &Config, is_recording: bool) -> bool {
+        if !is_recording {
+            self.recording = false;
+            self.speech_started_at = None;
+            self.below_threshold_since = None;
+            self.speech_duration = std::time::Duration::ZERO;
+            self.last_observed_at = None;
             return false;
         }
-    } else if is_dev_mode() {
-        log::info!("Using Python daemon in dev mode");
-        let script_path = format!("{}/python/backend_daemon.py", DEV_PROJECT_PATH);
-        cmd = Command::new(DEV_PYTHON_PATH);
-        cmd.arg(&script_path);
-    } else {
-        log::error!("Cannot determine daemon path!");
-        return false;
+        if !self.recording {
+            self.recording = true;
+            self.speech_started_at = None;
+            self.below_threshold_since = None;
+            self.speech_duration = std::time::Duration::ZERO;
+            self.last_observed_at = None;
+        }
+
+        if !config.use_vad {
+            return false;
+        }
+
+        let scaled = level * config.mic_sensitivity as f64;
+        let now = Instant::now();
+        let since_last = self.last_observed_at.replace(now).map(|prev| now - prev);
+
+        if scaled >= config.silence_threshold as f64 {
+            self.speech_started_at.get_or_insert(now);
+            // Time since the previous level counts as speech.
+            self.speech_duration += since_last.unwrap_or_default();
+            self.below_threshold_since = None;
+            return false;
+        }
+
+        if self.speech_started_at.is_none() {
+            // Never heard any speech yet, so silence doesn't count.
+            return false;
+        }
+        if self.speech_duration.as_millis() < config.min_speech_ms as u128 {
+            return false;
+        }
+
+        let below_since = *self.below_threshold_since.get_or_insert(now);
+        below_since.elapsed().as_millis() >= config.silence_timeout_ms as u128
     }
-    
-    cmd.stdin(Stdio::piped())
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped());
-    
-    match cmd.spawn() {
-        Ok(mut child) => {
-            log::info!("Started Python daemon (PID: {:?})", child.id());
-            
-            let stdin = child.stdin.take();
-            
-            // Spawn thread to read daemon output
-            if let Some(stdout) = child.stdout.take() {
-                let app_handle = app.clone();
-                std::thread::spawn(move || {
-                    let reader = BufReader::new(stdout);
-                    for line in reader.lines() {
-                        if let Ok(line) = line {
-                            if let Ok(json) = serde_json::from_str::<serde_json::Value>(&line) {
-                                // Audio level updates
-                                if let Some(level) = json.get("audio_level").and_then(|l| l.as_f64()) {
-                                    let _ = app_handle.emit("audio_level", level);
-                                }
-                                // Status updates
-                                if let Some(status) = json.get("status").and_then(|s| s.as_str()) {
-                                    log::info!("Daemon status: {}", status);
-                                    if status == "model_loaded" {
-                                        let _ = app_handle.emit("model_ready", ());
-                                    }
-                                }
-                                // Transcription result
-                                if let Some(text) = json.get("text").and_then(|t| t.as_str()) {
-                                    log::info!("Transcription result: {}", text);
-                                    let transcription_time = json.get("transcription_time")
-                                        .and_then(|t| t.as_f64())
-                                        .unwrap_or(0.0);
-                                    log::info!("Transcription took: {:.2}s", transcription_time);
-                                    
-                                    let _ = app_handle.emit("transcription_done", serde_json::json!({
-                                        "text": text,
-                                        "copied": json.get("copied").and_then(|c| c.as_bool()).unwrap_or(false),
-                                        "typed": json.get("typed").and_then(|t| t.as_bool()).unwrap_or(false)
-                                    }));
-                                    
-                                    // Don't hide overlay - let it stay visible
-                                }
-                                // Error
-                                if let Some(error) = json.get("error").and_then(|e| e.as_str()) {
-                                    log::warn!("Daemon error: {}", error);
-                                    let _ = app_handle.emit("transcription_done", serde_json::json!({
-                                        "text": "",
-                                        "error": error
-                                    }));
-                                    
-                                    // Don't hide overlay - let it stay visible
-                                }
-                            }
-                        }
+}
+
+/// Subscribes to the daemon's typed event stream and re-emits each event as
+/// the Tauri-facing event the frontend already listens for. Also drives VAD
+/// auto-stop off the audio level stream when `Config::use_vad` is enabled.
+fn spawn_event_forwarder(app: AppHandle, state: SharedState, daemon: DaemonHandle) {
+    let mut events = daemon.subscribe();
+    let mut vad = VadTracker::default();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            };
+
+            match event {
+                DaemonEvent::AudioLevel(level) => {
+                    let _ = app.emit("audio_level", level);
+
+                    let (config, is_recording) = {
+                        let guard = state.lock().await;
+                        (guard.config.clone(), guard.is_recording)
+                    };
+
+                    if vad.observe(level, &config, is_recording) {
+                        log::info!("VAD detected silence, auto-stopping recording");
+                        stop_recording(&app, &state).await;
                     }
-                    log::warn!("Daemon stdout reader ended");
-                });
+                }
+                DaemonEvent::AudioChunk(samples) => {
+                    let tx = state.lock().await.streaming_audio_tx.clone();
+                    if let Some(tx) = tx {
+                        let _ = tx.send(samples).await;
+                    }
+                }
+                DaemonEvent::Status(status) => {
+                    log::info!("Daemon status: {}", status);
+                    if status == "model_loaded" {
+                        state.lock().await.model_loaded = true;
+                        let _ = app.emit("model_ready", ());
+                    }
+                }
+                DaemonEvent::Transcription {
+                    text,
+                    copied,
+                    typed,
+                    transcription_time,
+                } => {
+                    log::info!(
+                        "Transcription result ({:.2}s): {}",
+                        transcription_time,
+                        text
+                    );
+                    let _ = app.emit(
+                        "transcription_done",
+                        serde_json::json!({
+                            "text": text,
+                            "copied": copied,
+                            "typed": typed,
+                        }),
+                    );
+                }
+                DaemonEvent::Error(error) => {
+                    log::warn!("Daemon error: {}", error);
+                    let _ = app.emit(
+                        "transcription_done",
+                        serde_json::json!({
+                            "text": "",
+                            "error": error,
+                        }),
+                    );
+                }
             }
-            
-            // Store stdin for sending commands
-            let state_clone = state.clone();
-            tauri::async_runtime::spawn(async move {
-                let mut state = state_clone.lock().await;
-                state.daemon_stdin = stdin;
-            });
-            
-            true
-        }
-        Err(e) => {
-            log::error!("Failed to start Python daemon: {}", e);
-            false
         }
+    });
+}
+
+/// Starts a recording the same way whether it was triggered by a push-to-talk
+/// key press or by a toggle-mode key press.
+async fn start_recording(app: &AppHandle, state: &SharedState) {
+    let mut guard = state.lock().await;
+
+    if guard.is_recording {
+        return;
+    }
+
+    guard.is_recording = true;
+    guard.recording_start = Some(Instant::now());
+
+    let device = guard.config.device_id;
+    let daemon = guard.daemon.clone();
+    let overlay_position = guard.config.overlay_position;
+
+    let (audio_tx, audio_rx) = mpsc::channel(64);
+    guard.streaming = Some(streaming::start(app.clone(), audio_rx));
+    guard.streaming_audio_tx = Some(audio_tx);
+
+    drop(guard);
+
+    if let Some(daemon) = daemon {
+        let _ = daemon.send(DaemonCommand::StartRecording { device }).await;
+    } else {
+        log::error!("Daemon not running!");
     }
+
+    log::info!("Recording started");
+
+    if let Some(window) = app.get_webview_window("overlay") {
+        // Re-anchor on the monitor under the cursor before showing, so the
+        // overlay lands on the display the user is actually dictating on
+        // rather than wherever it was last left.
+        position_overlay(&window, overlay_position);
+        let _ = window.show();
+    }
+
+    let _ = app.emit("recording_started", ());
 }
 
-fn send_daemon_command(stdin: &mut std::process::ChildStdin, cmd: &serde_json::Value) -> bool {
-    let json_str = serde_json::to_string(cmd).unwrap_or_default();
-    if let Err(e) = writeln!(stdin, "{}", json_str) {
-        log::error!("Failed to send command to daemon: {}", e);
-        return false;
+/// Parses a `"modifier+modifier+key"` hotkey string (e.g. `"alt+space"`,
+/// `"cmd+shift+r"`) into a registerable `Shortcut`.
+fn parse_hotkey(hotkey: &str) -> Option<Shortcut> {
+    let mut modifiers = Modifiers::empty();
+    let mut code = None;
+
+    for part in hotkey.split('+') {
+        match part.trim().to_lowercase().as_str() {
+            "cmd" | "command" | "super" | "meta" => modifiers |= Modifiers::SUPER,
+            "alt" | "option" => modifiers |= Modifiers::ALT,
+            "ctrl" | "control" => modifiers |= Modifiers::CONTROL,
+            "shift" => modifiers |= Modifiers::SHIFT,
+            key => code = key_code_from_str(key),
+        }
     }
-    if let Err(e) = stdin.flush() {
-        log::error!("Failed to flush daemon stdin: {}", e);
-        return false;
+
+    let code = code?;
+    let modifiers = if modifiers.is_empty() {
+        None
+    } else {
+        Some(modifiers)
+    };
+    Some(Shortcut::new(modifiers, code))
+}
+
+fn key_code_from_str(key: &str) -> Option<Code> {
+    match key {
+        "space" => Some(Code::Space),
+        "enter" | "return" => Some(Code::Enter),
+        "tab" => Some(Code::Tab),
+        "escape" | "esc" => Some(Code::Escape),
+        "backspace" => Some(Code::Backspace),
+        "up" => Some(Code::ArrowUp),
+        "down" => Some(Code::ArrowDown),
+        "left" => Some(Code::ArrowLeft),
+        "right" => Some(Code::ArrowRight),
+        "0" => Some(Code::Digit0),
+        "1" => Some(Code::Digit1),
+        "2" => Some(Code::Digit2),
+        "3" => Some(Code::Digit3),
+        "4" => Some(Code::Digit4),
+        "5" => Some(Code::Digit5),
+        "6" => Some(Code::Digit6),
+        "7" => Some(Code::Digit7),
+        "8" => Some(Code::Digit8),
+        "9" => Some(Code::Digit9),
+        "a" => Some(Code::KeyA),
+        "b" => Some(Code::KeyB),
+        "c" => Some(Code::KeyC),
+        "d" => Some(Code::KeyD),
+        "e" => Some(Code::KeyE),
+        "f" => Some(Code::KeyF),
+        "g" => Some(Code::KeyG),
+        "h" => Some(Code::KeyH),
+        "i" => Some(Code::KeyI),
+        "j" => Some(Code::KeyJ),
+        "k" => Some(Code::KeyK),
+        "l" => Some(Code::KeyL),
+        "m" => Some(Code::KeyM),
+        "n" => Some(Code::KeyN),
+        "o" => Some(Code::KeyO),
+        "p" => Some(Code::KeyP),
+        "q" => Some(Code::KeyQ),
+        "r" => Some(Code::KeyR),
+        "s" => Some(Code::KeyS),
+        "t" => Some(Code::KeyT),
+        "u" => Some(Code::KeyU),
+        "v" => Some(Code::KeyV),
+        "w" => Some(Code::KeyW),
+        "x" => Some(Code::KeyX),
+        "y" => Some(Code::KeyY),
+        "z" => Some(Code::KeyZ),
+        _ => None,
     }
-    true
 }
 
-fn setup_global_shortcut(app: &AppHandle, state: SharedState) -> Result<(), Box<dyn std::error::Error>> {
-    let app_handle = app.clone();
-    
-    // Use Option+Space as the hotkey
-    let shortcut = Shortcut::new(Some(Modifiers::ALT), Code::Space);
-    
-    app.global_shortcut().on_shortcut(shortcut, move |_app, _shortcut, event| {
+/// Builds the global shortcut handler: push-to-talk starts on press and
+/// stops on release, toggle mode starts/stops alternately on each press.
+fn make_shortcut_handler(
+    app_handle: AppHandle,
+    state: SharedState,
+) -> impl Fn(&AppHandle, &Shortcut, tauri_plugin_global_shortcut::ShortcutEvent) + Send + Sync + 'static
+{
+    move |_app, _shortcut, event| {
         let app_clone = app_handle.clone();
         let state_clone = state.clone();
-        
+
         match event.state() {
             ShortcutState::Pressed => {
-                let app_clone2 = app_clone.clone();
-                let state_clone2 = state_clone.clone();
-                
                 tauri::async_runtime::spawn(async move {
-                    let mut state = state_clone2.lock().await;
-                    
-                    if state.is_recording {
-                        return;
-                    }
-                    
-                    state.is_recording = true;
-                    state.recording_start = Some(Instant::now());
-                    
-                    // Get config before mutable borrow
-                    let device = state.config.device_id;
-                    
-                    // Send start_recording command to daemon
-                    if let Some(ref mut stdin) = state.daemon_stdin {
-                        let cmd = serde_json::json!({
-                            "cmd": "start_recording",
-                            "device": device
-                        });
-                        send_daemon_command(stdin, &cmd);
-                    } else {
-                        log::error!("Daemon not running!");
-                    }
-                    
-                    log::info!("Recording started");
-                    
-                    // Show overlay
-                    if let Some(window) = app_clone2.get_webview_window("overlay") {
-                        let _ = window.show();
+                    let (record_mode, is_recording) = {
+                        let guard = state_clone.lock().await;
+                        (guard.config.record_mode, guard.is_recording)
+                    };
+
+                    match record_mode {
+                        RecordMode::PushToTalk => start_recording(&app_clone, &state_clone).await,
+                        RecordMode::Toggle => {
+                            if is_recording {
+                                stop_recording(&app_clone, &state_clone).await;
+                            } else {
+                                start_recording(&app_clone, &state_clone).await;
+                            }
+                        }
                     }
-                    
-                    let _ = app_clone2.emit("recording_started", ());
                 });
             }
             ShortcutState::Released => {
-                let app_clone2 = app_clone.clone();
-                let state_clone2 = state_clone.clone();
-                
                 tauri::async_runtime::spawn(async move {
-                    let mut state = state_clone2.lock().await;
-                    
-                    if !state.is_recording {
-                        return;
-                    }
-                    
-                    state.is_recording = false;
-                    
-                    let duration = state.recording_start
-                        .map(|start| start.elapsed().as_secs_f32())
-                        .unwrap_or(0.0);
-                    
-                    log::info!("Recording stopped after {:.1}s", duration);
-                    
-                    let _ = app_clone2.emit("recording_stopped", serde_json::json!({
-                        "duration": duration
-                    }));
-                    
-                    // Get config before mutable borrow
-                    let output_mode = state.config.output_mode.clone();
-                    
-                    // Send stop_and_transcribe command to daemon
-                    if let Some(ref mut stdin) = state.daemon_stdin {
-                        let _ = app_clone2.emit("transcription_started", ());
-                        
-                        let cmd = serde_json::json!({
-                            "cmd": "stop_and_transcribe",
-                            "output": output_mode
-                        });
-                        send_daemon_command(stdin, &cmd);
-                    } else {
-                        log::error!("Daemon not running!");
-                        
-                        // Hide overlay
-                        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
-                        if let Some(window) = app_clone2.get_webview_window("overlay") {
-                            let _ = window.hide();
-                        }
+                    let record_mode = state_clone.lock().await.config.record_mode;
+                    if record_mode == RecordMode::PushToTalk {
+                        stop_recording(&app_clone, &state_clone).await;
                     }
                 });
             }
         }
-    })?;
-    
-    log::info!("Global shortcut registered: Option+Space (hold to record, release to transcribe)");
+    }
+}
+
+/// Unregisters whatever shortcut is currently bound and registers `hotkey` in
+/// its place, so the binding can change at runtime without a restart.
+fn register_hotkey(app: &AppHandle, state: SharedState, hotkey: &str) -> Result<(), String> {
+    let shortcut = parse_hotkey(hotkey).ok_or_else(|| format!("Invalid hotkey: {}", hotkey))?;
+
+    app.global_shortcut()
+        .unregister_all()
+        .map_err(|e| e.to_string())?;
+    app.global_shortcut()
+        .on_shortcut(shortcut, make_shortcut_handler(app.clone(), state))
+        .map_err(|e| e.to_string())?;
+
+    log::info!("Global shortcut registered: {}", hotkey);
 
     Ok(())
 }
 
-#[allow(dead_code)]
-async fn spawn_python_backend(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
-    let sidecar = app.shell().sidecar("superwhisper-backend")?;
-    let (mut _rx, mut _child) = sidecar.spawn()?;
-    log::info!("Python backend started");
+fn setup_global_shortcut(app: &AppHandle, state: SharedState) -> Result<(), Box<dyn std::error::Error>> {
+    let hotkey = state.blocking_lock().config.hotkey.clone();
+    register_hotkey(app, state, &hotkey)?;
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_hotkey(
+    app: AppHandle,
+    hotkey: String,
+    state: tauri::State<'_, SharedState>,
+) -> Result<(), String> {
+    log::info!("Setting hotkey: {}", hotkey);
+
+    register_hotkey(&app, state.inner().clone(), &hotkey)?;
+
+    let mut guard = state.lock().await;
+    guard.config.hotkey = hotkey;
+    let config = guard.config.clone();
+    drop(guard);
+
+    save_config_to_file(&config)?;
+
     Ok(())
 }
 
@@ -633,6 +1061,7 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .plugin(tauri_plugin_store::Builder::new().build())
         .manage(state.clone())
         .setup(move |app| {
             if cfg!(debug_assertions) {
@@ -652,49 +1081,38 @@ pub fn run() {
                 log::error!("Failed to setup global shortcut: {}", e);
             }
             
-            // Start daemon and load model
+            // Start the supervised daemon and load the configured model. The
+            // supervisor re-issues this LoadModel command automatically if
+            // the daemon crashes and restarts later on.
             let state_clone = state.clone();
             let app_handle = app.handle().clone();
-            std::thread::spawn(move || {
-                // Start daemon
-                if start_daemon(&app_handle, state_clone.clone()) {
-                    // Give daemon time to start
-                    std::thread::sleep(std::time::Duration::from_millis(500));
-                    
-                    // Load model
-                    let config = tauri::async_runtime::block_on(async {
-                        let state = state_clone.lock().await;
-                        state.config.clone()
-                    });
-                    
-                    tauri::async_runtime::block_on(async {
-                        let mut state = state_clone.lock().await;
-                        if let Some(ref mut stdin) = state.daemon_stdin {
-                            let cmd = serde_json::json!({
-                                "cmd": "load_model",
-                                "model": config.model
-                            });
-                            send_daemon_command(stdin, &cmd);
-                            state.model_loaded = true;
-                            log::info!("Model load command sent: {}", config.model);
-                        }
-                    });
-                }
+            tauri::async_runtime::spawn(async move {
+                let model = {
+                    let state = state_clone.lock().await;
+                    state.config.model.clone()
+                };
+
+                let daemon = daemon::spawn_supervised(app_handle.clone(), model.clone());
+                spawn_event_forwarder(app_handle.clone(), state_clone.clone(), daemon.clone());
+
+                let mut state = state_clone.lock().await;
+                state.daemon = Some(daemon);
+                state.model_loaded = false;
+                log::info!("Model load command sent: {}", model);
             });
 
-            // Center overlay at top of screen
+            http_api::spawn(app.handle().clone(), state.clone());
+
+            // Reconfigure the overlay into a non-activating panel (macOS only,
+            // no-op elsewhere) before positioning it and applying the rest of
+            // its visibility/click-through config.
             if let Some(window) = app.get_webview_window("overlay") {
-                if let Some(monitor) = window.current_monitor().ok().flatten() {
-                    let screen_width = monitor.size().width as i32;
-                    let window_width = 400;
-                    let x = (screen_width - window_width) / 2;
-                    let y = 30; // Below menu bar
-                    let _ = window.set_position(tauri::PhysicalPosition::new(x, y));
-                    log::info!("Overlay positioned at ({}, {})", x, y);
-                }
+                window.make_overlay_panel();
+                let config = state.blocking_lock().config.clone();
+                apply_overlay_window_config(&window, &config);
             }
 
-            log::info!("SuperWhisper initialized - Hold Option+Space to record");
+            log::info!("SuperWhisper initialized");
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -708,7 +1126,72 @@ pub fn run() {
             open_accessibility_settings,
             check_model_status,
             download_model,
+            set_hotkey,
+            models::list_models,
+            models::download_catalog_model,
+            models::delete_model,
+            reposition_overlay,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vad_config() -> Config {
+        Config {
+            use_vad: true,
+            mic_sensitivity: 1.0,
+            silence_threshold: 0.5,
+            min_speech_ms: 0,
+            silence_timeout_ms: 0,
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn vad_tracker_resets_on_non_recording() {
+        let mut vad = VadTracker::default();
+        let config = vad_config();
+        assert!(!vad.observe(0.0, &config, false));
+        assert!(vad.speech_started_at.is_none());
+        assert!(vad.below_threshold_since.is_none());
+    }
+
+    #[test]
+    fn vad_tracker_ignores_levels_when_disabled() {
+        let mut vad = VadTracker::default();
+        let config = Config {
+            use_vad: false,
+            ..vad_config()
+        };
+        assert!(!vad.observe(0.0, &config, true));
+    }
+
+    #[test]
+    fn vad_tracker_does_not_trigger_before_any_speech() {
+        let mut vad = VadTracker::default();
+        let config = vad_config();
+        // Silence from the very start of the recording shouldn't auto-stop;
+        // nothing has been said yet for the stop to be "after".
+        assert!(!vad.observe(0.0, &config, true));
+    }
+
+    #[test]
+    fn vad_tracker_triggers_after_speech_then_silence() {
+        let mut vad = VadTracker::default();
+        let config = vad_config();
+        assert!(!vad.observe(1.0, &config, true));
+        assert!(vad.observe(0.0, &config, true));
+    }
+
+    #[test]
+    fn vad_tracker_does_not_trigger_while_still_speaking() {
+        let mut vad = VadTracker::default();
+        let config = vad_config();
+        assert!(!vad.observe(1.0, &config, true));
+        assert!(!vad.observe(1.0, &config, true));
+    }
+}
@@ -0,0 +1,223 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_shell::process::CommandEvent;
+use tauri_plugin_store::StoreExt;
+
+use crate::spawn_backend_command;
+
+const STORE_FILE: &str = "models.json";
+
+/// Catalog of whisper variants SuperWhisper knows how to fetch, along with
+/// the digest used to verify each download before it's marked ready.
+struct ModelCatalogEntry {
+    id: &'static str,
+    label: &'static str,
+    size_bytes: u64,
+    sha256: &'static str,
+}
+
+const MODEL_CATALOG: &[ModelCatalogEntry] = &[
+    ModelCatalogEntry {
+        id: "tiny",
+        label: "Tiny",
+        size_bytes: 75_000_000,
+        sha256: "8950abfda7b727630760dd35bcf5c3daa7631aff223a90f7728c0d2521dde10c",
+    },
+    ModelCatalogEntry {
+        id: "base",
+        label: "Base",
+        size_bytes: 142_000_000,
+        sha256: "cae662172fd450bb0cd710a769079c05bfc5d8e35efa6576edc7d0377afdd4a2",
+    },
+    ModelCatalogEntry {
+        id: "small",
+        label: "Small",
+        size_bytes: 466_000_000,
+        sha256: "81db8ebbbbc69c6c6ad4a6aa92b76e0c08af547da236b9e2c9dbe1d8285a8130",
+    },
+    ModelCatalogEntry {
+        id: "medium",
+        label: "Medium",
+        size_bytes: 1_500_000_000,
+        sha256: "c082456a7766e23a18db084cd34b6ff510baef506548b897cc80e9b7d3e121c8",
+    },
+    ModelCatalogEntry {
+        id: "large",
+        label: "Large",
+        size_bytes: 2_900_000_000,
+        sha256: "d35c416a85b807e9b5384915d6ebb4a9f7352713efd89857b45a242f473728a9",
+    },
+    ModelCatalogEntry {
+        id: "large-q5",
+        label: "Large (quantized, q5_0)",
+        size_bytes: 1_100_000_000,
+        sha256: "f27587aaad71afa18301f290fcebce343cde379250d8eed7837b1d50526510e9",
+    },
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelInfo {
+    pub id: String,
+    pub label: String,
+    pub size_bytes: u64,
+    pub downloaded: bool,
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredModel {
+    path: String,
+    sha256: String,
+}
+
+fn models_dir(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("models");
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+fn lookup(id: &str) -> Result<&'static ModelCatalogEntry, String> {
+    MODEL_CATALOG
+        .iter()
+        .find(|entry| entry.id == id)
+        .ok_or_else(|| format!("Unknown model: {}", id))
+}
+
+fn stored(app: &AppHandle, id: &str) -> Result<Option<StoredModel>, String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    Ok(store
+        .get(id)
+        .and_then(|value| serde_json::from_value(value.clone()).ok()))
+}
+
+/// Lists the full catalog, annotated with whether each model has already
+/// been downloaded (and verified) according to the store.
+#[tauri::command]
+pub async fn list_models(app: AppHandle) -> Result<Vec<ModelInfo>, String> {
+    let mut models = Vec::with_capacity(MODEL_CATALOG.len());
+    for entry in MODEL_CATALOG {
+        let existing = stored(&app, entry.id)?;
+        models.push(ModelInfo {
+            id: entry.id.to_string(),
+            label: entry.label.to_string(),
+            size_bytes: entry.size_bytes,
+            downloaded: existing.is_some(),
+            path: existing.map(|m| m.path),
+        });
+    }
+    Ok(models)
+}
+
+/// Downloads a catalog model into the app's data directory, streaming
+/// progress as `model-download-progress` events, then verifies its SHA-256
+/// digest before recording it in the store as ready. A failed checksum
+/// deletes the partial/corrupt file rather than marking it available.
+#[tauri::command]
+pub async fn download_catalog_model(app: AppHandle, id: String) -> Result<(), String> {
+    let entry = lookup(&id)?;
+    let dest = models_dir(&app)?.join(format!("{}.bin", entry.id));
+
+    let (mut rx, _child) = spawn_backend_command(
+        &app,
+        vec![
+            "--download-model".to_string(),
+            entry.id.to_string(),
+            "--output".to_string(),
+            dest.to_string_lossy().into_owned(),
+        ],
+    )?;
+
+    while let Some(event) = rx.recv().await {
+        match event {
+            CommandEvent::Stdout(line) => {
+                let line = String::from_utf8_lossy(&line);
+                if let Some((downloaded, total)) = serde_json::from_str::<serde_json::Value>(&line)
+                    .ok()
+                    .and_then(|json| {
+                        let downloaded = json.get("downloaded").and_then(|v| v.as_u64())?;
+                        let total = json.get("total").and_then(|v| v.as_u64())?;
+                        Some((downloaded, total))
+                    })
+                {
+                    let percent = if total > 0 {
+                        downloaded as f32 / total as f32 * 100.0
+                    } else {
+                        0.0
+                    };
+                    let _ = app.emit(
+                        "model-download-progress",
+                        serde_json::json!({
+                            "id": entry.id,
+                            "downloaded": downloaded,
+                            "total": total,
+                            "percent": percent,
+                        }),
+                    );
+                }
+            }
+            CommandEvent::Stderr(line) => {
+                log::warn!("Backend stderr: {}", String::from_utf8_lossy(&line));
+            }
+            CommandEvent::Error(e) => return Err(e),
+            CommandEvent::Terminated(payload) => {
+                if payload.code.unwrap_or(1) != 0 {
+                    return Err(format!("Download failed with status {:?}", payload.code));
+                }
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    let digest = sha256_file(&dest)?;
+    if digest != entry.sha256 {
+        let _ = std::fs::remove_file(&dest);
+        return Err(format!(
+            "Checksum mismatch for {}: expected {}, got {}",
+            entry.id, entry.sha256, digest
+        ));
+    }
+
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(
+        entry.id,
+        serde_json::to_value(StoredModel {
+            path: dest.to_string_lossy().into_owned(),
+            sha256: digest,
+        })
+        .map_err(|e| e.to_string())?,
+    );
+    store.save().map_err(|e| e.to_string())?;
+
+    log::info!("Model verified and ready: {}", entry.id);
+    Ok(())
+}
+
+/// Removes a downloaded model's file and store entry so Settings can free up
+/// disk space without the user hunting down the file manually.
+#[tauri::command]
+pub async fn delete_model(app: AppHandle, id: String) -> Result<(), String> {
+    lookup(&id)?;
+
+    if let Some(model) = stored(&app, &id)? {
+        std::fs::remove_file(&model.path).map_err(|e| e.to_string())?;
+    }
+
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.delete(&id);
+    store.save().map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+fn sha256_file(path: &std::path::Path) -> Result<String, String> {
+    let bytes = std::fs::read(path).map_err(|e| e.to_string())?;
+    let mut hasher = Sha256::new();
+    hasher.update(&bytes);
+    Ok(format!("{:x}", hasher.finalize()))
+}
@@ -0,0 +1,266 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use tauri::{AppHandle, Emitter};
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::interval;
+
+use crate::transcribe_pcm;
+
+const SAMPLE_RATE: usize = 16_000;
+const RING_CAPACITY_SAMPLES: usize = SAMPLE_RATE * 30;
+const WINDOW_SAMPLES: usize = SAMPLE_RATE * 8;
+const OVERLAP_SAMPLES: usize = SAMPLE_RATE / 2;
+const DECODE_INTERVAL: Duration = Duration::from_millis(500);
+const SILENCE_GAP: Duration = Duration::from_millis(700);
+const SILENCE_RMS_THRESHOLD: f32 = 0.02;
+
+/// Ring buffer of raw PCM audio backing the sliding decode window. Capacity
+/// is a safety net (30s); in practice `commit` keeps it close to just the
+/// still-uncommitted audio.
+struct StreamBuffer {
+    samples: VecDeque<i16>,
+}
+
+impl StreamBuffer {
+    fn new() -> Self {
+        Self {
+            samples: VecDeque::with_capacity(RING_CAPACITY_SAMPLES),
+        }
+    }
+
+    fn push(&mut self, chunk: &[i16]) {
+        self.samples.extend(chunk.iter().copied());
+        while self.samples.len() > RING_CAPACITY_SAMPLES {
+            self.samples.pop_front();
+        }
+    }
+
+    /// The current decode window: the tail of the buffer, clamped to
+    /// `WINDOW_SAMPLES` so decode latency stays bounded.
+    fn window(&self) -> Vec<i16> {
+        let len = self.samples.len().min(WINDOW_SAMPLES);
+        self.samples.iter().skip(self.samples.len() - len).copied().collect()
+    }
+
+    /// Advances the window past `committed_samples` of now-final audio,
+    /// keeping a small overlap for decoder context so the next window isn't
+    /// missing onset audio. `committed_samples` is always the length of the
+    /// just-decoded tail window. Dropping down to the overlap is relative to
+    /// the *buffer's* current length, not a fixed prefix count, so any
+    /// backlog beyond `WINDOW_SAMPLES` (e.g. a long run before the first
+    /// commit) sits in front of the tail window and is dropped with it,
+    /// undecoded, instead of staying at the front while the just-committed
+    /// tail survives to be re-decoded.
+    fn commit(&mut self, committed_samples: usize) {
+        let keep = OVERLAP_SAMPLES.min(committed_samples);
+        let drop_count = self.samples.len().saturating_sub(keep);
+        for _ in 0..drop_count {
+            self.samples.pop_front();
+        }
+    }
+
+    fn rms_of_last(&self, duration: Duration) -> f32 {
+        let n = (SAMPLE_RATE as u128 * duration.as_millis() / 1000) as usize;
+        let n = n.min(self.samples.len());
+        if n == 0 {
+            return 0.0;
+        }
+        let sum_sq: f64 = self
+            .samples
+            .iter()
+            .rev()
+            .take(n)
+            .map(|&s| (s as f64 / i16::MAX as f64).powi(2))
+            .sum();
+        (sum_sq / n as f64).sqrt() as f32
+    }
+}
+
+/// Handle to a running streaming-transcription session. Dropping or calling
+/// `stop` ends the session and discards its ring buffer.
+pub struct StreamingHandle {
+    stop: oneshot::Sender<()>,
+}
+
+impl StreamingHandle {
+    pub fn stop(self) {
+        let _ = self.stop.send(());
+    }
+}
+
+/// Decodes one window of raw PCM by handing it to the backend's one-shot PCM
+/// transcription mode through `transcribe_pcm`, which runs in a separate
+/// backend process rather than the daemon.
+async fn decode_window(app: &AppHandle, samples: &[i16]) -> Option<String> {
+    let mut bytes = Vec::with_capacity(samples.len() * 2);
+    for sample in samples {
+        bytes.extend_from_slice(&sample.to_le_bytes());
+    }
+    transcribe_pcm(app, &bytes).await
+}
+
+/// Finds the longest word-level suffix of `previous_final` that recurs as a
+/// prefix of `hypothesis`, and strips it from `hypothesis`. `StreamBuffer::
+/// commit` deliberately keeps `OVERLAP_SAMPLES` of committed audio so the
+/// next window has decoder context, which means that audio gets
+/// re-transcribed as the start of the next hypothesis; without this, the
+/// words it produces would be emitted twice.
+fn strip_committed_overlap(previous_final: &str, hypothesis: &str) -> String {
+    let prev_words: Vec<&str> = previous_final.split_whitespace().collect();
+    let hyp_words: Vec<&str> = hypothesis.split_whitespace().collect();
+
+    let max_overlap = prev_words.len().min(hyp_words.len());
+    for k in (1..=max_overlap).rev() {
+        if prev_words[prev_words.len() - k..] == hyp_words[..k] {
+            return hyp_words[k..].join(" ");
+        }
+    }
+    hypothesis.to_string()
+}
+
+/// Starts a streaming-transcription session: decodes the sliding window on
+/// a ~500ms tick, emits each hypothesis as `transcript-partial`, and
+/// "commits" it as `transcript-final` once it stabilizes across two
+/// consecutive decodes or a silence gap is detected, advancing the window
+/// so committed text is never re-emitted; only the short overlap that
+/// `StreamBuffer::commit` keeps is decoded again.
+pub fn start(app: AppHandle, mut audio_rx: mpsc::Receiver<Vec<i16>>) -> StreamingHandle {
+    let (stop_tx, mut stop_rx) = oneshot::channel();
+
+    tauri::async_runtime::spawn(async move {
+        let mut buffer = StreamBuffer::new();
+        let mut ticker = interval(DECODE_INTERVAL);
+        let mut last_hypothesis = String::new();
+        let mut last_final = String::new();
+        let mut stable_hits = 0u32;
+
+        loop {
+            tokio::select! {
+                _ = &mut stop_rx => break,
+                maybe_chunk = audio_rx.recv() => {
+                    match maybe_chunk {
+                        Some(chunk) => buffer.push(&chunk),
+                        None => break,
+                    }
+                }
+                _ = ticker.tick() => {
+                    let window = buffer.window();
+                    if window.is_empty() {
+                        continue;
+                    }
+
+                    let Some(hypothesis) = decode_window(&app, &window).await else {
+                        continue;
+                    };
+                    if hypothesis.trim().is_empty() {
+                        continue;
+                    }
+
+                    let _ = app.emit("transcript-partial", &hypothesis);
+
+                    if hypothesis == last_hypothesis {
+                        stable_hits += 1;
+                    } else {
+                        stable_hits = 0;
+                        last_hypothesis = hypothesis.clone();
+                    }
+
+                    let silence_gap = buffer.rms_of_last(SILENCE_GAP) < SILENCE_RMS_THRESHOLD;
+
+                    if stable_hits >= 1 || silence_gap {
+                        let new_text = strip_committed_overlap(&last_final, &hypothesis);
+                        if !new_text.trim().is_empty() {
+                            let _ = app.emit("transcript-final", &new_text);
+                        }
+                        last_final = hypothesis.clone();
+                        buffer.commit(window.len());
+                        last_hypothesis.clear();
+                        stable_hits = 0;
+                    }
+                }
+            }
+        }
+
+        log::info!("Streaming transcription session ended");
+    });
+
+    StreamingHandle { stop: stop_tx }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_returns_whole_buffer_below_capacity() {
+        let mut buffer = StreamBuffer::new();
+        buffer.push(&[1, 2, 3]);
+        assert_eq!(buffer.window(), vec![1, 2, 3]);
+    }
+
+    /// Wrap-safe sample values (cycling through 0..1000) so slices of the
+    /// sequence can be compared positionally (raw indices don't fit in i16
+    /// past ~32k).
+    fn sequence(len: usize) -> Vec<i16> {
+        (0..len).map(|i| (i % 1000) as i16).collect()
+    }
+
+    #[test]
+    fn window_clamps_to_the_tail_once_over_capacity() {
+        let mut buffer = StreamBuffer::new();
+        let samples = sequence(WINDOW_SAMPLES + 100);
+        buffer.push(&samples);
+        let window = buffer.window();
+        assert_eq!(window.len(), WINDOW_SAMPLES);
+        assert_eq!(window, samples[samples.len() - WINDOW_SAMPLES..]);
+    }
+
+    #[test]
+    fn commit_keeps_only_the_overlap_even_with_backlog_beyond_the_window() {
+        // More than WINDOW_SAMPLES accumulated before the first commit, as
+        // happens during a long run of continuous speech. commit() must size
+        // what it drops relative to the buffer's length; dropping a fixed
+        // prefix count instead would leave stale front audio while the
+        // committed tail survived to be re-decoded.
+        let mut buffer = StreamBuffer::new();
+        let samples = sequence(WINDOW_SAMPLES + 1000);
+        buffer.push(&samples);
+        let window = buffer.window();
+
+        buffer.commit(window.len());
+
+        assert_eq!(buffer.samples.len(), OVERLAP_SAMPLES);
+        let expected_overlap = &samples[samples.len() - OVERLAP_SAMPLES..];
+        assert_eq!(buffer.samples.iter().copied().collect::<Vec<_>>(), expected_overlap);
+    }
+
+    #[test]
+    fn commit_keeps_the_whole_window_when_shorter_than_the_overlap() {
+        let mut buffer = StreamBuffer::new();
+        buffer.push(&[1, 2, 3]);
+        let window = buffer.window();
+
+        buffer.commit(window.len());
+
+        assert_eq!(buffer.samples.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn strip_committed_overlap_removes_the_reheard_prefix() {
+        let stripped = strip_committed_overlap("hello there friend", "there friend how are you");
+        assert_eq!(stripped, "how are you");
+    }
+
+    #[test]
+    fn strip_committed_overlap_keeps_hypothesis_without_overlap() {
+        let stripped = strip_committed_overlap("hello there friend", "completely different words");
+        assert_eq!(stripped, "completely different words");
+    }
+
+    #[test]
+    fn strip_committed_overlap_handles_empty_previous_final() {
+        let stripped = strip_committed_overlap("", "first words ever");
+        assert_eq!(stripped, "first words ever");
+    }
+}
@@ -0,0 +1,55 @@
+use tauri::WebviewWindow;
+
+/// Extension trait that reconfigures a window's underlying NSWindow into a
+/// non-activating panel that floats above fullscreen Spaces. This is what
+/// lets the overlay stay visible while a fullscreen video call or editor has
+/// focus, without ever stealing that focus itself. No-op on non-macOS
+/// platforms.
+pub trait WindowExt {
+    fn make_overlay_panel(&self);
+}
+
+impl WindowExt for WebviewWindow {
+    fn make_overlay_panel(&self) {
+        #[cfg(target_os = "macos")]
+        macos::make_overlay_panel(self);
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod macos {
+    use cocoa::appkit::{NSMainMenuWindowLevel, NSWindow, NSWindowCollectionBehavior};
+    use cocoa::base::id;
+    use tauri::WebviewWindow;
+
+    // NSWindowStyleMask.NonactivatingPanel, not exposed by the `cocoa` crate.
+    const NS_WINDOW_STYLE_MASK_NONACTIVATING_PANEL: usize = 1 << 7;
+
+    pub fn make_overlay_panel(window: &WebviewWindow) {
+        let Ok(ns_window) = window.ns_window() else {
+            log::warn!("No NSWindow handle for overlay, skipping panel setup");
+            return;
+        };
+
+        unsafe {
+            let ns_window = ns_window as id;
+
+            let style_mask: usize = msg_send_style_mask(ns_window);
+            ns_window.setStyleMask_(style_mask | NS_WINDOW_STYLE_MASK_NONACTIVATING_PANEL);
+
+            ns_window.setCollectionBehavior_(
+                NSWindowCollectionBehavior::NSWindowCollectionBehaviorCanJoinAllSpaces
+                    | NSWindowCollectionBehavior::NSWindowCollectionBehaviorFullScreenAuxiliary,
+            );
+
+            ns_window.setLevel_((NSMainMenuWindowLevel + 1) as i64);
+        }
+
+        log::info!("Overlay reconfigured as a non-activating floating panel");
+    }
+
+    unsafe fn msg_send_style_mask(ns_window: id) -> usize {
+        use objc::{msg_send, sel, sel_impl};
+        msg_send![ns_window, styleMask]
+    }
+}